@@ -0,0 +1,176 @@
+//! Provider backend abstraction for talking to heterogeneous model servers.
+//!
+//! `ZeroClaw` can be pointed at more than one kind of model server at once
+//! (a local TGI box, an Ollama instance, anything OpenAI-compatible, or
+//! Anthropic directly). Each speaks a slightly different request schema, so
+//! this module classifies a configured provider into a [`ProviderKind`] and
+//! knows how to build that kind's native request body from a prompt and a
+//! set of generation parameters, via the [`Backend`] trait.
+
+use serde_json::{json, Value};
+
+/// The kind of model server a provider endpoint speaks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProviderKind {
+    Tgi,
+    Ollama,
+    OpenAiCompat,
+    Anthropic,
+}
+
+/// Generation parameters shared across all provider request schemas.
+#[derive(Debug, Clone)]
+pub struct RequestParams {
+    pub max_tokens: u32,
+    pub temperature: f32,
+    pub top_p: f32,
+    pub stop: Vec<String>,
+}
+
+impl Default for RequestParams {
+    fn default() -> Self {
+        Self {
+            max_tokens: 512,
+            temperature: 0.7,
+            top_p: 1.0,
+            stop: Vec::new(),
+        }
+    }
+}
+
+/// Knows how to turn a prompt into a provider-native request body.
+pub trait Backend {
+    fn build_request_body(&self, model: &str, prompt: &str, params: &RequestParams) -> Value;
+}
+
+impl Backend for ProviderKind {
+    fn build_request_body(&self, model: &str, prompt: &str, params: &RequestParams) -> Value {
+        match self {
+            ProviderKind::Tgi => json!({
+                "inputs": prompt,
+                "parameters": {
+                    "max_new_tokens": params.max_tokens,
+                    "temperature": params.temperature,
+                    "do_sample": params.temperature > 0.0,
+                    "top_p": params.top_p,
+                    "stop_tokens": params.stop,
+                }
+            }),
+            ProviderKind::Ollama => json!({
+                "model": model,
+                "prompt": prompt,
+                "options": {
+                    "num_predict": params.max_tokens,
+                    "temperature": params.temperature,
+                    "top_p": params.top_p,
+                    "stop": params.stop,
+                },
+                "stream": false,
+            }),
+            ProviderKind::OpenAiCompat => json!({
+                "model": model,
+                "messages": [{"role": "user", "content": prompt}],
+                "max_tokens": params.max_tokens,
+                "temperature": params.temperature,
+                "top_p": params.top_p,
+                "stop": params.stop,
+            }),
+            ProviderKind::Anthropic => json!({
+                "model": model,
+                "max_tokens": params.max_tokens,
+                "temperature": params.temperature,
+                "top_p": params.top_p,
+                "stop_sequences": params.stop,
+                "messages": [{"role": "user", "content": prompt}],
+            }),
+        }
+    }
+}
+
+/// Classifies a `models.providers.<name>` config entry into a [`ProviderKind`].
+///
+/// Prefers an explicit `kind` field; falls back to heuristics on `baseUrl`
+/// for configs written before the `kind` field existed.
+pub fn classify_provider(node: &Value) -> ProviderKind {
+    if let Some(kind) = node.get("kind").and_then(Value::as_str) {
+        return match kind.to_ascii_lowercase().as_str() {
+            "tgi" => ProviderKind::Tgi,
+            "ollama" => ProviderKind::Ollama,
+            "anthropic" => ProviderKind::Anthropic,
+            _ => ProviderKind::OpenAiCompat,
+        };
+    }
+
+    let base_url = node
+        .get("baseUrl")
+        .and_then(Value::as_str)
+        .unwrap_or_default();
+
+    if base_url.contains("anthropic.com") {
+        ProviderKind::Anthropic
+    } else if base_url.contains(":11434") || base_url.contains("ollama") {
+        ProviderKind::Ollama
+    } else if base_url.contains("/generate") || base_url.contains("text-generation-inference") {
+        ProviderKind::Tgi
+    } else {
+        ProviderKind::OpenAiCompat
+    }
+}
+
+/// A single named provider entry parsed from `models.providers`.
+#[derive(Debug, Clone)]
+pub struct NamedProvider {
+    pub name: String,
+    pub kind: ProviderKind,
+    pub base_url: Option<String>,
+    pub api_key: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifies_explicit_kind() {
+        let node = json!({"kind": "tgi", "baseUrl": "http://example"});
+        assert_eq!(classify_provider(&node), ProviderKind::Tgi);
+    }
+
+    #[test]
+    fn classifies_ollama_by_port_heuristic() {
+        let node = json!({"baseUrl": "http://localhost:11434"});
+        assert_eq!(classify_provider(&node), ProviderKind::Ollama);
+    }
+
+    #[test]
+    fn defaults_to_openai_compat() {
+        let node = json!({"baseUrl": "https://api.example.com/v1"});
+        assert_eq!(classify_provider(&node), ProviderKind::OpenAiCompat);
+    }
+
+    #[test]
+    fn tgi_builds_native_schema() {
+        let body =
+            ProviderKind::Tgi.build_request_body("ignored", "hi", &RequestParams::default());
+        assert_eq!(body["inputs"], "hi");
+        assert!(body["parameters"]["max_new_tokens"].is_number());
+    }
+
+    #[test]
+    fn openai_compat_builds_messages_schema() {
+        let body =
+            ProviderKind::OpenAiCompat.build_request_body("gpt", "hi", &RequestParams::default());
+        assert_eq!(body["model"], "gpt");
+        assert_eq!(body["messages"][0]["content"], "hi");
+    }
+
+    #[test]
+    fn anthropic_builds_stop_sequences_not_stop() {
+        let params = RequestParams {
+            stop: vec!["</done>".to_string()],
+            ..RequestParams::default()
+        };
+        let body = ProviderKind::Anthropic.build_request_body("claude", "hi", &params);
+        assert_eq!(body["stop_sequences"][0], "</done>");
+    }
+}
@@ -1,42 +1,167 @@
-use std::path::{Path, PathBuf};
+use std::path::Path;
 use std::fs;
+use std::time::Duration;
 use serde::{Deserialize, Serialize};
-use chrono::Utc;
+use chrono::{DateTime, Utc};
+
+mod retrieval;
+mod search;
+mod signing;
+
+pub use retrieval::{record_recovery, suggest_recovery};
+pub use search::{search_tasks, TaskFilter, TaskHit};
+pub use signing::{decode_crash_jws, encode_crash_jws, verify_crash};
+
+/// How many crashes the ring buffer remembers before dropping the oldest.
+const MAX_CRASHES: usize = 50;
+/// Sliding window over which recent crashes are counted for backoff.
+const BACKOFF_WINDOW_MINUTES: i64 = 10;
+/// Crashes within the window above this count trigger backoff.
+const BACKOFF_THRESHOLD: usize = 3;
+const BACKOFF_BASE_SECS: u64 = 5;
+const BACKOFF_MAX_SECS: u64 = 300;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct CrashInfo {
     pub timestamp: String,
     pub error: String,
+    /// Recovery note attached when this entry came from `suggest_recovery`
+    /// rather than a fresh crash.
+    #[serde(default)]
+    pub recovery_detail: Option<String>,
+    /// Detached Ed25519 signature over the fields above, base64url-encoded.
+    #[serde(default)]
+    pub signature: Option<String>,
+    /// The base64url-encoded public key the signature was made with.
+    #[serde(default)]
+    pub pubkey: Option<String>,
+}
+
+/// Whether the supervisor should restart the process now or back off.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RestartDecision {
+    Proceed,
+    Backoff(Duration),
+}
+
+/// File the Windows/systemd supervisor wrappers read before each restart
+/// attempt to learn how long to wait.
+const BACKOFF_STATE_FILE: &str = "restart_backoff_secs";
+
+fn crashes_file(workspace_dir: &Path) -> std::path::PathBuf {
+    workspace_dir.join("crashes.json")
+}
+
+/// Path to the backoff state file supervisor wrappers poll before
+/// restarting the daemon. Exposed so `service::handle_command` can point
+/// its generated wrappers at the same workspace `resilience` writes to.
+pub fn restart_backoff_file(workspace_dir: &Path) -> std::path::PathBuf {
+    workspace_dir.join(BACKOFF_STATE_FILE)
+}
+
+/// Recomputes `should_attempt_restart` and writes the result to the backoff
+/// state file. Called from `record_crash`, `consume_crash` and
+/// `service::start`; the supervisor wrappers' own restart loops do not call
+/// it, so between those calls the file keeps the last value written.
+pub fn refresh_backoff_state(workspace_dir: &Path) {
+    let secs = match should_attempt_restart(workspace_dir) {
+        RestartDecision::Proceed => 0,
+        RestartDecision::Backoff(duration) => duration.as_secs(),
+    };
+    let _ = fs::write(restart_backoff_file(workspace_dir), secs.to_string());
+}
+
+fn load_crashes(workspace_dir: &Path) -> Vec<CrashInfo> {
+    fs::read_to_string(crashes_file(workspace_dir))
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn save_crashes(crashes: &[CrashInfo], workspace_dir: &Path) {
+    if let Ok(json) = serde_json::to_string_pretty(crashes) {
+        let _ = fs::write(crashes_file(workspace_dir), json);
+    }
 }
 
 pub fn record_crash(error: &str, workspace_dir: &Path) {
-    let crash_file = workspace_dir.join("last_crash.json");
-    let info = CrashInfo {
+    let mut crashes = load_crashes(workspace_dir);
+    // Attach whatever fixed the most similar past crash, so whoever consumes
+    // this entry next has a lead before falling back to a cold escalation.
+    let recovery_detail = retrieval::suggest_recovery(error, workspace_dir)
+        .into_iter()
+        .next()
+        .and_then(|hit| hit.recovery_detail);
+
+    let mut info = CrashInfo {
         timestamp: Utc::now().to_rfc3339(),
         error: error.to_string(),
+        recovery_detail,
+        signature: None,
+        pubkey: None,
     };
+    signing::sign_crash(&mut info, workspace_dir);
+    crashes.push(info);
 
-    if let Ok(json) = serde_json::to_string_pretty(&info) {
-        let _ = fs::write(crash_file, json);
+    if crashes.len() > MAX_CRASHES {
+        let overflow = crashes.len() - MAX_CRASHES;
+        crashes.drain(0..overflow);
     }
+
+    save_crashes(&crashes, workspace_dir);
+    refresh_backoff_state(workspace_dir);
 }
 
+/// Removes and returns the most recent crash, if any. The rest of the ring
+/// buffer is left in place so `should_attempt_restart` still sees the earlier
+/// crashes for crash-loop detection; the consumed one is no longer counted.
 pub fn consume_crash(workspace_dir: &Path) -> Option<CrashInfo> {
-    let crash_file = workspace_dir.join("last_crash.json");
-    if crash_file.exists() {
-        if let Ok(content) = fs::read_to_string(&crash_file) {
-            let _ = fs::remove_file(&crash_file);
-            return serde_json::from_str(&content).ok();
-        }
+    let mut crashes = load_crashes(workspace_dir);
+    let latest = crashes.pop()?;
+    save_crashes(&crashes, workspace_dir);
+    refresh_backoff_state(workspace_dir);
+
+    if !signing::verify_crash(&latest) {
+        eprintln!("⚠️  crash report signature failed verification, treating as untrusted");
+    }
+
+    Some(latest)
+}
+
+/// Inspects the recent crash window and decides whether a supervisor should
+/// restart the process now or back off. More than `BACKOFF_THRESHOLD`
+/// crashes inside `BACKOFF_WINDOW_MINUTES` triggers an exponential backoff,
+/// `base * 2^(recent_crashes - threshold)`, capped at `BACKOFF_MAX_SECS`.
+pub fn should_attempt_restart(workspace_dir: &Path) -> RestartDecision {
+    let crashes = load_crashes(workspace_dir);
+    let window_start = Utc::now() - chrono::Duration::minutes(BACKOFF_WINDOW_MINUTES);
+
+    let recent_crashes = crashes
+        .iter()
+        .filter(|c| {
+            DateTime::parse_from_rfc3339(&c.timestamp)
+                .map(|ts| ts.with_timezone(&Utc) >= window_start)
+                .unwrap_or(false)
+        })
+        .count();
+
+    if recent_crashes > BACKOFF_THRESHOLD {
+        let exponent = u32::try_from(recent_crashes - BACKOFF_THRESHOLD).unwrap_or(u32::MAX);
+        let secs = BACKOFF_BASE_SECS
+            .saturating_mul(2u64.saturating_pow(exponent))
+            .min(BACKOFF_MAX_SECS);
+        RestartDecision::Backoff(Duration::from_secs(secs))
+    } else {
+        RestartDecision::Proceed
     }
-    None
 }
 
 pub fn report_task(title: &str, detail: &str, workspace_dir: &Path) {
     let tasks_file = workspace_dir.join("RESILIENCE_TASKS.md");
     let timestamp = Utc::now().to_rfc3339();
+    let kind = if detail.contains("fatal") { "FATAL" } else { "RECOVERY" };
     let entry = format!("\n## [{}] {}\n\n- **Time**: {}\n- **Detail**: {}\n\n---",
-        if detail.contains("fatal") { "FATAL" } else { "RECOVERY" },
+        kind,
         title,
         timestamp,
         detail
@@ -50,4 +175,204 @@ pub fn report_task(title: &str, detail: &str, workspace_dir: &Path) {
 
     content.push_str(&entry);
     let _ = fs::write(tasks_file, content);
+
+    search::index_task(kind, title, &timestamp, detail, workspace_dir);
+}
+
+/// Records that `recovery_detail` is a verified fix for `error` — the caller
+/// confirmed the remediation actually worked, not just that some component
+/// logged a non-fatal message. Logs the event the same way `report_task`
+/// does (so it still shows up in `RESILIENCE_TASKS.md` and the search
+/// index), and additionally feeds `retrieval::record_recovery` so future
+/// similar crashes can be matched against it via `suggest_recovery`.
+///
+/// This is deliberately separate from `report_task`'s `RECOVERY`/`FATAL`
+/// split: that split is a cheap `contains("fatal")` guess over whatever
+/// message an `Observer` happened to report, which isn't a strong enough
+/// signal that `detail` is a real fix worth feeding back into retrieval.
+///
+/// No production call site narrates a verified recovery yet — this tree
+/// doesn't have one — so `recovery_store.jsonl` stays empty until one
+/// exists. That's a real gap, not one this function can close on its own;
+/// it exists so that caller, whenever it's built, has a correctly-gated
+/// entry point to call instead of the blanket `RECOVERY`-kind wiring this
+/// replaces.
+pub fn report_recovery(error: &str, recovery_detail: &str, workspace_dir: &Path) {
+    report_task(error, recovery_detail, workspace_dir);
+    retrieval::record_recovery(error, recovery_detail, workspace_dir);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn crash_at(minutes_ago: i64) -> CrashInfo {
+        CrashInfo {
+            timestamp: (Utc::now() - chrono::Duration::minutes(minutes_ago)).to_rfc3339(),
+            error: format!("crash {minutes_ago}m ago"),
+            recovery_detail: None,
+            signature: None,
+            pubkey: None,
+        }
+    }
+
+    #[test]
+    fn ring_buffer_drops_oldest_past_max_crashes() {
+        let dir = tempdir().unwrap();
+        for i in 0..(MAX_CRASHES + 5) {
+            record_crash(&format!("crash {i}"), dir.path());
+        }
+
+        let crashes = load_crashes(dir.path());
+        assert_eq!(crashes.len(), MAX_CRASHES);
+        assert_eq!(crashes.first().unwrap().error, "crash 5");
+        assert_eq!(crashes.last().unwrap().error, format!("crash {}", MAX_CRASHES + 4));
+    }
+
+    #[test]
+    fn consume_crash_pops_latest_and_keeps_rest_for_backoff_analysis() {
+        let dir = tempdir().unwrap();
+        record_crash("first", dir.path());
+        record_crash("second", dir.path());
+
+        let consumed = consume_crash(dir.path()).expect("a crash was recorded");
+        assert_eq!(consumed.error, "second");
+
+        let remaining = load_crashes(dir.path());
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].error, "first");
+    }
+
+    #[test]
+    fn consume_crash_returns_none_on_empty_buffer() {
+        let dir = tempdir().unwrap();
+        assert!(consume_crash(dir.path()).is_none());
+    }
+
+    #[test]
+    fn should_attempt_restart_proceeds_at_exactly_the_threshold() {
+        let dir = tempdir().unwrap();
+        let crashes: Vec<CrashInfo> = (0..BACKOFF_THRESHOLD).map(|_| crash_at(1)).collect();
+        save_crashes(&crashes, dir.path());
+
+        assert_eq!(should_attempt_restart(dir.path()), RestartDecision::Proceed);
+    }
+
+    #[test]
+    fn should_attempt_restart_backs_off_one_past_the_threshold() {
+        let dir = tempdir().unwrap();
+        let crashes: Vec<CrashInfo> = (0..=BACKOFF_THRESHOLD).map(|_| crash_at(1)).collect();
+        save_crashes(&crashes, dir.path());
+
+        match should_attempt_restart(dir.path()) {
+            RestartDecision::Backoff(duration) => {
+                assert_eq!(duration, Duration::from_secs(BACKOFF_BASE_SECS * 2));
+            }
+            RestartDecision::Proceed => panic!("expected backoff one crash past the threshold"),
+        }
+    }
+
+    #[test]
+    fn should_attempt_restart_ignores_crashes_outside_the_window() {
+        let dir = tempdir().unwrap();
+        let crashes: Vec<CrashInfo> = (0..10).map(|_| crash_at(BACKOFF_WINDOW_MINUTES + 5)).collect();
+        save_crashes(&crashes, dir.path());
+
+        assert_eq!(should_attempt_restart(dir.path()), RestartDecision::Proceed);
+    }
+
+    #[test]
+    fn record_crash_writes_backoff_state_for_supervisor_wrappers() {
+        let dir = tempdir().unwrap();
+        for i in 0..(BACKOFF_THRESHOLD + 2) {
+            record_crash(&format!("crash {i}"), dir.path());
+        }
+
+        let secs: u64 = fs::read_to_string(restart_backoff_file(dir.path()))
+            .unwrap()
+            .trim()
+            .parse()
+            .unwrap();
+        assert!(secs > 0, "repeated crashes should produce a non-zero backoff");
+    }
+
+    #[test]
+    fn consume_crash_refreshes_a_stale_backoff_state_after_the_storm_passed() {
+        let dir = tempdir().unwrap();
+        // A storm that has since fallen outside the window, like one that
+        // happened hours ago: `should_attempt_restart` would say `Proceed`
+        // if asked fresh, but the backoff file was never told that.
+        let crashes: Vec<CrashInfo> = (0..(BACKOFF_THRESHOLD + 5))
+            .map(|_| crash_at(BACKOFF_WINDOW_MINUTES + 5))
+            .collect();
+        save_crashes(&crashes, dir.path());
+        fs::write(restart_backoff_file(dir.path()), "300").unwrap();
+
+        consume_crash(dir.path());
+
+        let secs: u64 = fs::read_to_string(restart_backoff_file(dir.path()))
+            .unwrap()
+            .trim()
+            .parse()
+            .unwrap();
+        assert_eq!(secs, 0, "a cooled-down storm should not leave a stale backoff");
+    }
+
+    #[test]
+    fn should_attempt_restart_caps_backoff_at_max_secs() {
+        let dir = tempdir().unwrap();
+        let crashes: Vec<CrashInfo> = (0..(BACKOFF_THRESHOLD + 20)).map(|_| crash_at(1)).collect();
+        save_crashes(&crashes, dir.path());
+
+        match should_attempt_restart(dir.path()) {
+            RestartDecision::Backoff(duration) => {
+                assert_eq!(duration, Duration::from_secs(BACKOFF_MAX_SECS));
+            }
+            RestartDecision::Proceed => panic!("expected backoff with this many recent crashes"),
+        }
+    }
+
+    #[test]
+    fn report_recovery_feeds_the_retrieval_store() {
+        let dir = tempdir().unwrap();
+        report_recovery("socket timeout", "increase socket timeout to 30s", dir.path());
+
+        let hits = suggest_recovery("socket timeout", dir.path());
+        assert_eq!(
+            hits.first().and_then(|h| h.recovery_detail.as_deref()),
+            Some("increase socket timeout to 30s")
+        );
+    }
+
+    #[test]
+    fn report_task_never_feeds_the_retrieval_store_on_its_own() {
+        let dir = tempdir().unwrap();
+        // report_task's RECOVERY/FATAL split is just a `contains("fatal")`
+        // guess over whatever an Observer reported — not a verified fix, so
+        // it must never reach retrieval on its own.
+        report_task("oom", "fatal: out of memory", dir.path());
+        report_task("socket timeout", "retried the connection", dir.path());
+
+        assert!(suggest_recovery("oom", dir.path()).is_empty());
+        assert!(suggest_recovery("socket timeout", dir.path()).is_empty());
+    }
+
+    #[test]
+    fn record_crash_fills_in_recovery_detail_from_a_prior_similar_fix() {
+        let dir = tempdir().unwrap();
+        report_recovery(
+            "connection timed out waiting for socket",
+            "increase socket timeout to 30s",
+            dir.path(),
+        );
+
+        record_crash("connection timed out waiting for handshake", dir.path());
+
+        let crashes = load_crashes(dir.path());
+        assert_eq!(
+            crashes.last().unwrap().recovery_detail.as_deref(),
+            Some("increase socket timeout to 30s")
+        );
+    }
 }
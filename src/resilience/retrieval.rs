@@ -0,0 +1,251 @@
+//! Retrieval-augmented recovery: when a crash happens, look up similar past
+//! failures and surface the recovery notes that worked for them, instead of
+//! escalating blind.
+//!
+//! Entries are kept in a flat JSONL store (`recovery_store.jsonl`) rather
+//! than a database, matching the rest of this module's file-backed state.
+//! Each entry carries an embedding of its error text, from the configured
+//! provider's embeddings endpoint when that is reachable and otherwise a
+//! local hashed-trigram vector; queries are embedded the same way.
+
+use std::fs;
+use std::path::Path;
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+use super::CrashInfo;
+
+const STORE_FILE: &str = "recovery_store.jsonl";
+const TOP_K: usize = 3;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct RecoveryRecord {
+    timestamp: String,
+    error_text: String,
+    recovery_detail: String,
+    embedding: Vec<f32>,
+}
+
+/// Records that `recovery_detail` fixed `error`, so future similar crashes
+/// can be matched against it.
+pub fn record_recovery(error: &str, recovery_detail: &str, workspace_dir: &Path) {
+    let record = RecoveryRecord {
+        timestamp: Utc::now().to_rfc3339(),
+        error_text: error.to_string(),
+        recovery_detail: recovery_detail.to_string(),
+        embedding: embed_error(error),
+    };
+
+    let Ok(line) = serde_json::to_string(&record) else {
+        return;
+    };
+
+    let store_file = workspace_dir.join(STORE_FILE);
+    let mut content = fs::read_to_string(&store_file).unwrap_or_default();
+    content.push_str(&line);
+    content.push('\n');
+    let _ = fs::write(store_file, content);
+}
+
+/// Finds the `TOP_K` prior crashes most similar to `error` and returns their
+/// recovery notes, most similar first. Empty only if the store is missing or
+/// holds no readable entries; there is no minimum similarity, so entries
+/// unrelated to `error` are returned too when nothing closer is stored.
+pub fn suggest_recovery(error: &str, workspace_dir: &Path) -> Vec<CrashInfo> {
+    let store_file = workspace_dir.join(STORE_FILE);
+    let Ok(content) = fs::read_to_string(&store_file) else {
+        return Vec::new();
+    };
+
+    let query = embed_error(error);
+    let mut scored: Vec<(f32, RecoveryRecord)> = content
+        .lines()
+        .filter_map(|line| serde_json::from_str::<RecoveryRecord>(line).ok())
+        .map(|record| {
+            let score = cosine_similarity(&query, &record.embedding);
+            (score, record)
+        })
+        .collect();
+
+    scored.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(std::cmp::Ordering::Equal));
+
+    scored
+        .into_iter()
+        .take(TOP_K)
+        .map(|(_, record)| CrashInfo {
+            timestamp: record.timestamp,
+            error: record.error_text,
+            recovery_detail: Some(record.recovery_detail),
+            signature: None,
+            pubkey: None,
+        })
+        .collect()
+}
+
+/// Embeds an error string for similarity search. Tries the configured
+/// provider's embeddings endpoint first, falling back to a cheap local
+/// hashed-trigram vector when offline or unconfigured.
+fn embed_error(text: &str) -> Vec<f32> {
+    embed_via_provider(text).unwrap_or_else(|| hashed_trigram_vector(text))
+}
+
+/// Request timeout for the provider embeddings call. Crash recording must
+/// stay fast even when the configured provider is the thing that's down, so
+/// this is deliberately short rather than the default (infinite) ureq wait.
+const EMBEDDINGS_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(2);
+
+/// Calls the configured provider's embeddings endpoint (`{baseUrl}/embeddings`,
+/// `{"model", "input"}` in, `data[0].embedding` out). Only attempted for an
+/// `OpenAiCompat` primary provider, since that's the only `ProviderKind` this
+/// request/response shape actually matches — Ollama's embeddings API takes a
+/// different body and Anthropic has no embeddings endpoint at all, so other
+/// kinds fall straight through to the local vector instead of firing a
+/// request that's guaranteed to fail.
+///
+/// Returns `None` — and `embed_error` falls back to the local
+/// hashed-trigram vector — whenever there's no OpenClaw profile, the
+/// primary provider isn't `OpenAiCompat`, or the request fails or times out,
+/// so retrieval keeps working fully offline.
+fn embed_via_provider(text: &str) -> Option<Vec<f32>> {
+    let profile = crate::util::detect_openclaw_profile()?;
+    let base_url = profile.api_url?;
+    let provider_name = profile.provider.as_deref()?;
+    let provider = profile.providers.iter().find(|p| p.name == provider_name)?;
+    if provider.kind != crate::providers::ProviderKind::OpenAiCompat {
+        return None;
+    }
+    let model = profile.model.unwrap_or_default();
+
+    let mut request = ureq::post(&format!("{base_url}/embeddings")).timeout(EMBEDDINGS_TIMEOUT);
+    if let Some(api_key) = &profile.api_key {
+        request = request.set("Authorization", &format!("Bearer {api_key}"));
+    }
+
+    let response: serde_json::Value = request
+        .send_json(serde_json::json!({ "model": model, "input": text }))
+        .ok()?
+        .into_json()
+        .ok()?;
+
+    response
+        .get("data")?
+        .get(0)?
+        .get("embedding")?
+        .as_array()?
+        .iter()
+        .map(|v| v.as_f64().map(|f| f as f32))
+        .collect()
+}
+
+const VECTOR_DIM: usize = 256;
+
+/// A cheap, dependency-free bag-of-hashed-trigrams embedding. Good enough to
+/// rank "this error looks like that one" without calling out to a model.
+fn hashed_trigram_vector(text: &str) -> Vec<f32> {
+    let normalized = text.to_ascii_lowercase();
+    let chars: Vec<char> = normalized.chars().collect();
+    let mut vector = vec![0.0f32; VECTOR_DIM];
+
+    if chars.len() < 3 {
+        return vector;
+    }
+
+    for window in chars.windows(3) {
+        let trigram: String = window.iter().collect();
+        let bucket = hash_str(&trigram) as usize % VECTOR_DIM;
+        vector[bucket] += 1.0;
+    }
+
+    let norm = vector.iter().map(|v| v * v).sum::<f32>().sqrt();
+    if norm > 0.0 {
+        for v in &mut vector {
+            *v /= norm;
+        }
+    }
+    vector
+}
+
+fn hash_str(s: &str) -> u64 {
+    // FNV-1a: simple, stable across runs, no extra dependency.
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in s.as_bytes() {
+        hash ^= u64::from(*byte);
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+
+/// Cosine similarity, guarded against zero-norm vectors (empty or
+/// too-short error strings) so it never divides by zero, and against
+/// mismatched dimensions (e.g. a provider-embedded record compared against
+/// the local hashed-trigram fallback) so it never silently scores a
+/// truncated subset of one vector instead of refusing to compare.
+fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    if a.len() != b.len() {
+        return 0.0;
+    }
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm_a = a.iter().map(|v| v * v).sum::<f32>().sqrt();
+    let norm_b = b.iter().map(|v| v * v).sum::<f32>().sqrt();
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return 0.0;
+    }
+    dot / (norm_a * norm_b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn empty_store_returns_no_suggestions() {
+        let dir = tempdir().unwrap();
+        assert!(suggest_recovery("anything", dir.path()).is_empty());
+    }
+
+    #[test]
+    fn finds_similar_prior_crash() {
+        let dir = tempdir().unwrap();
+        record_recovery(
+            "connection timed out waiting for socket",
+            "increase socket timeout to 30s",
+            dir.path(),
+        );
+        record_recovery(
+            "out of memory allocating buffer",
+            "reduce batch size",
+            dir.path(),
+        );
+
+        let hits = suggest_recovery("connection timed out waiting for handshake", dir.path());
+        assert!(!hits.is_empty());
+        assert_eq!(
+            hits[0].recovery_detail.as_deref(),
+            Some("increase socket timeout to 30s")
+        );
+    }
+
+    #[test]
+    fn embed_via_provider_is_none_without_an_openclaw_profile() {
+        // No ~/.openclaw or ~/.clawdbot config in this environment, so there's
+        // no provider to call and embed_error must fall back cleanly.
+        assert!(embed_via_provider("anything").is_none());
+    }
+
+    #[test]
+    fn cosine_similarity_handles_zero_vectors() {
+        let zero = vec![0.0f32; VECTOR_DIM];
+        let other = hashed_trigram_vector("some error");
+        assert_eq!(cosine_similarity(&zero, &other), 0.0);
+        assert_eq!(cosine_similarity(&zero, &zero), 0.0);
+    }
+
+    #[test]
+    fn cosine_similarity_refuses_mismatched_dimensions() {
+        let short = vec![1.0f32; 4];
+        let long = vec![1.0f32; VECTOR_DIM];
+        assert_eq!(cosine_similarity(&short, &long), 0.0);
+    }
+}
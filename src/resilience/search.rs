@@ -0,0 +1,319 @@
+//! Full-text search over `RESILIENCE_TASKS.md`, backed by a small tantivy
+//! index kept alongside it in the workspace directory.
+//!
+//! The markdown file stays the source of truth and the human-readable log;
+//! this index is a queryable side-car updated incrementally from
+//! `report_task` so callers can ask "show fatal crashes mentioning 'timeout'
+//! in the last week" instead of grepping the file.
+
+use std::ops::Bound;
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use tantivy::collector::TopDocs;
+use tantivy::directory::MmapDirectory;
+use tantivy::query::{BooleanQuery, FuzzyTermQuery, Occur, Query, QueryParser, RangeQuery, TermQuery};
+use tantivy::schema::{Document, Field, IndexRecordOption, Schema, FAST, INDEXED, STORED, STRING, TEXT};
+use tantivy::{doc, Index, Term};
+
+const INDEX_DIR: &str = ".resilience_index";
+const WRITER_HEAP_BYTES: usize = 15_000_000;
+const MAX_HITS: usize = 50;
+const TIMESTAMP_EPOCH_FIELD: &str = "timestamp_epoch";
+
+/// Filters applied alongside the free-text query.
+#[derive(Debug, Clone, Default)]
+pub struct TaskFilter {
+    pub kind: Option<String>,
+    pub since: Option<DateTime<Utc>>,
+    pub until: Option<DateTime<Utc>>,
+}
+
+/// A single matched task entry.
+#[derive(Debug, Clone)]
+pub struct TaskHit {
+    pub kind: String,
+    pub title: String,
+    pub timestamp: String,
+    pub detail: String,
+    pub score: f32,
+}
+
+fn build_schema() -> Schema {
+    let mut builder = Schema::builder();
+    builder.add_text_field("kind", STRING | STORED);
+    builder.add_text_field("title", TEXT | STORED);
+    builder.add_text_field("timestamp", STRING | STORED);
+    builder.add_text_field("detail", TEXT | STORED);
+    // Separate from the human-readable `timestamp` string so range queries
+    // ("last week") can be pushed into the query itself instead of
+    // post-filtering a `TopDocs`-capped result set.
+    builder.add_i64_field(TIMESTAMP_EPOCH_FIELD, INDEXED | STORED | FAST);
+    builder.build()
+}
+
+fn open_or_create_index(workspace_dir: &Path) -> tantivy::Result<Index> {
+    let index_path = workspace_dir.join(INDEX_DIR);
+    std::fs::create_dir_all(&index_path)?;
+    let directory = MmapDirectory::open(&index_path)?;
+    Index::open_or_create(directory, build_schema())
+}
+
+struct Fields {
+    kind: Field,
+    title: Field,
+    timestamp: Field,
+    timestamp_epoch: Field,
+    detail: Field,
+}
+
+fn fields(index: &Index) -> Fields {
+    let schema = index.schema();
+    Fields {
+        kind: schema.get_field("kind").expect("kind field exists"),
+        title: schema.get_field("title").expect("title field exists"),
+        timestamp: schema
+            .get_field("timestamp")
+            .expect("timestamp field exists"),
+        timestamp_epoch: schema
+            .get_field(TIMESTAMP_EPOCH_FIELD)
+            .expect("timestamp_epoch field exists"),
+        detail: schema.get_field("detail").expect("detail field exists"),
+    }
+}
+
+/// Canonicalizes a task kind (`FATAL`/`RECOVERY`) so indexing and filtering
+/// agree regardless of caller casing, the way `providers::classify_provider`
+/// canonicalizes provider kind strings.
+fn normalize_kind(kind: &str) -> String {
+    kind.to_ascii_uppercase()
+}
+
+/// Adds one task entry to the index. Called from `report_task` right after
+/// the markdown append so the index never drifts from the file.
+pub fn index_task(kind: &str, title: &str, timestamp: &str, detail: &str, workspace_dir: &Path) {
+    let Ok(index) = open_or_create_index(workspace_dir) else {
+        return;
+    };
+    let f = fields(&index);
+    let Ok(mut writer) = index.writer(WRITER_HEAP_BYTES) else {
+        return;
+    };
+    let epoch = DateTime::parse_from_rfc3339(timestamp)
+        .map(|ts| ts.timestamp())
+        .unwrap_or(0);
+    let _ = writer.add_document(doc!(
+        f.kind => normalize_kind(kind),
+        f.title => title,
+        f.timestamp => timestamp,
+        f.timestamp_epoch => epoch,
+        f.detail => detail,
+    ));
+    let _ = writer.commit();
+}
+
+/// Typo-tolerant prefix search over task titles and details, filtered by
+/// `kind` and timestamp range.
+pub fn search_tasks(query: &str, filter: &TaskFilter, workspace_dir: &Path) -> Vec<TaskHit> {
+    let Ok(index) = open_or_create_index(workspace_dir) else {
+        return Vec::new();
+    };
+    let Ok(reader) = index.reader() else {
+        return Vec::new();
+    };
+    let searcher = reader.searcher();
+    let f = fields(&index);
+
+    let mut clauses: Vec<(Occur, Box<dyn Query>)> = Vec::new();
+
+    if query.trim().is_empty() {
+        clauses.push((Occur::Must, Box::new(tantivy::query::AllQuery)));
+    } else {
+        // OR an exact/parsed match together with a fuzzy-prefix match, so a
+        // plain typo (which `QueryParser` parses successfully into an exact
+        // `TermQuery` that simply matches nothing) still surfaces results
+        // instead of silently returning zero hits.
+        let parser = QueryParser::for_index(&index, vec![f.title, f.detail]);
+        let mut text_clauses: Vec<(Occur, Box<dyn Query>)> = Vec::new();
+
+        if let Ok(parsed) = parser.parse_query(query) {
+            text_clauses.push((Occur::Should, parsed));
+        }
+
+        let fuzzy_term = Term::from_field_text(f.title, &query.to_ascii_lowercase());
+        text_clauses.push((
+            Occur::Should,
+            Box::new(FuzzyTermQuery::new_prefix(fuzzy_term, 2, true)),
+        ));
+        let fuzzy_detail_term = Term::from_field_text(f.detail, &query.to_ascii_lowercase());
+        text_clauses.push((
+            Occur::Should,
+            Box::new(FuzzyTermQuery::new_prefix(fuzzy_detail_term, 2, true)),
+        ));
+
+        clauses.push((Occur::Must, Box::new(BooleanQuery::new(text_clauses))));
+    }
+
+    if let Some(kind) = &filter.kind {
+        let term = Term::from_field_text(f.kind, &normalize_kind(kind));
+        clauses.push((
+            Occur::Must,
+            Box::new(TermQuery::new(term, IndexRecordOption::Basic)),
+        ));
+    }
+
+    if filter.since.is_some() || filter.until.is_some() {
+        let lower = filter
+            .since
+            .map_or(Bound::Unbounded, |d| Bound::Included(d.timestamp()));
+        let upper = filter
+            .until
+            .map_or(Bound::Unbounded, |d| Bound::Included(d.timestamp()));
+        clauses.push((
+            Occur::Must,
+            Box::new(RangeQuery::new_i64_bounds(
+                TIMESTAMP_EPOCH_FIELD.to_string(),
+                lower,
+                upper,
+            )),
+        ));
+    }
+
+    let combined = BooleanQuery::new(clauses);
+    let Ok(top_docs) = searcher.search(&combined, &TopDocs::with_limit(MAX_HITS)) else {
+        return Vec::new();
+    };
+
+    top_docs
+        .into_iter()
+        .filter_map(|(score, address)| {
+            let retrieved: Document = searcher.doc(address).ok()?;
+            Some(TaskHit {
+                kind: field_text(&retrieved, f.kind),
+                title: field_text(&retrieved, f.title),
+                timestamp: field_text(&retrieved, f.timestamp),
+                detail: field_text(&retrieved, f.detail),
+                score,
+            })
+        })
+        .collect()
+}
+
+fn field_text(doc: &Document, field: Field) -> String {
+    doc.get_first(field)
+        .and_then(|v| v.as_text())
+        .unwrap_or_default()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration as ChronoDuration;
+    use tempfile::tempdir;
+
+    #[test]
+    fn finds_exact_text_match() {
+        let dir = tempdir().unwrap();
+        index_task(
+            "FATAL",
+            "Disk IO failure",
+            &Utc::now().to_rfc3339(),
+            "disk write timeout while flushing",
+            dir.path(),
+        );
+
+        let hits = search_tasks("timeout", &TaskFilter::default(), dir.path());
+        assert!(hits.iter().any(|h| h.title == "Disk IO failure"));
+    }
+
+    #[test]
+    fn fuzzy_fallback_matches_typo() {
+        let dir = tempdir().unwrap();
+        index_task(
+            "FATAL",
+            "Network failure",
+            &Utc::now().to_rfc3339(),
+            "connection timeout while polling",
+            dir.path(),
+        );
+
+        let hits = search_tasks("tiemout", &TaskFilter::default(), dir.path());
+        assert!(
+            !hits.is_empty(),
+            "a typoed query should still surface results via fuzzy-prefix matching"
+        );
+    }
+
+    #[test]
+    fn kind_filter_is_case_insensitive() {
+        let dir = tempdir().unwrap();
+        index_task(
+            "FATAL",
+            "Crash",
+            &Utc::now().to_rfc3339(),
+            "panic in worker",
+            dir.path(),
+        );
+
+        let filter = TaskFilter {
+            kind: Some("fatal".to_string()),
+            ..Default::default()
+        };
+        let hits = search_tasks("panic", &filter, dir.path());
+        assert!(!hits.is_empty());
+    }
+
+    #[test]
+    fn kind_filter_excludes_other_kinds() {
+        let dir = tempdir().unwrap();
+        index_task(
+            "RECOVERY",
+            "Restarted worker",
+            &Utc::now().to_rfc3339(),
+            "panic recovered",
+            dir.path(),
+        );
+
+        let filter = TaskFilter {
+            kind: Some("FATAL".to_string()),
+            ..Default::default()
+        };
+        let hits = search_tasks("panic", &filter, dir.path());
+        assert!(hits.is_empty());
+    }
+
+    #[test]
+    fn time_range_filter_is_not_crowded_out_by_top_hits_cap() {
+        let dir = tempdir().unwrap();
+        let old_ts = (Utc::now() - ChronoDuration::days(30)).to_rfc3339();
+        for i in 0..(MAX_HITS + 5) {
+            index_task(
+                "FATAL",
+                &format!("old crash {i}"),
+                &old_ts,
+                "timeout while connecting",
+                dir.path(),
+            );
+        }
+        let recent_ts = Utc::now().to_rfc3339();
+        index_task(
+            "FATAL",
+            "recent crash",
+            &recent_ts,
+            "timeout while connecting recently",
+            dir.path(),
+        );
+
+        let filter = TaskFilter {
+            since: Some(Utc::now() - ChronoDuration::days(1)),
+            ..Default::default()
+        };
+        let hits = search_tasks("timeout", &filter, dir.path());
+        assert!(
+            hits.iter().any(|h| h.title == "recent crash"),
+            "in-window match should not be crowded out by older docs exceeding MAX_HITS"
+        );
+        assert!(hits.iter().all(|h| h.title != "old crash 0"));
+    }
+}
@@ -0,0 +1,220 @@
+//! Tamper-evident crash reports: sign each [`CrashInfo`] with Ed25519 so a
+//! report can be shipped to and verified by an external collector without
+//! trusting the transport.
+//!
+//! The signing key lives in the workspace as `crash_signing_key` (raw
+//! 32-byte seed), generated on first use.
+
+use std::fs;
+use std::path::Path;
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use serde::Serialize;
+
+use super::CrashInfo;
+
+const KEY_FILE: &str = "crash_signing_key";
+
+#[derive(Serialize)]
+struct SignedPayload<'a> {
+    timestamp: &'a str,
+    error: &'a str,
+    recovery_detail: &'a Option<String>,
+}
+
+fn load_or_generate_keypair(workspace_dir: &Path) -> SigningKey {
+    let key_path = workspace_dir.join(KEY_FILE);
+    if let Ok(bytes) = fs::read(&key_path) {
+        if let Ok(seed) = <[u8; 32]>::try_from(bytes.as_slice()) {
+            return SigningKey::from_bytes(&seed);
+        }
+    }
+
+    let signing_key = SigningKey::generate(&mut rand::rngs::OsRng);
+    let _ = fs::write(&key_path, signing_key.to_bytes());
+    restrict_to_owner(&key_path);
+    signing_key
+}
+
+#[cfg(unix)]
+fn restrict_to_owner(path: &Path) {
+    use std::os::unix::fs::PermissionsExt;
+    let _ = fs::set_permissions(path, fs::Permissions::from_mode(0o600));
+}
+
+#[cfg(not(unix))]
+fn restrict_to_owner(_path: &Path) {}
+
+fn canonical_payload(info: &CrashInfo) -> Vec<u8> {
+    let payload = SignedPayload {
+        timestamp: &info.timestamp,
+        error: &info.error,
+        recovery_detail: &info.recovery_detail,
+    };
+    serde_json::to_vec(&payload).unwrap_or_default()
+}
+
+/// Signs `info` in place with the workspace's Ed25519 key, populating its
+/// `signature` and `pubkey` fields.
+pub fn sign_crash(info: &mut CrashInfo, workspace_dir: &Path) {
+    let signing_key = load_or_generate_keypair(workspace_dir);
+    let signature = signing_key.sign(&canonical_payload(info));
+    info.signature = Some(URL_SAFE_NO_PAD.encode(signature.to_bytes()));
+    info.pubkey = Some(URL_SAFE_NO_PAD.encode(signing_key.verifying_key().to_bytes()));
+}
+
+/// Verifies that `info`'s embedded signature matches its payload and
+/// embedded pubkey. Returns `false` for anything missing or malformed
+/// rather than erroring, since an invalid signature just means "don't
+/// trust this report".
+///
+/// This only proves the report wasn't altered after whoever holds that
+/// pubkey's private key signed it — it does not prove the pubkey belongs to
+/// a trusted workspace. A collector that wants provenance, not just
+/// tamper-evidence, must pin the expected pubkey(s) out-of-band and compare
+/// `info.pubkey` against that allowlist itself.
+pub fn verify_crash(info: &CrashInfo) -> bool {
+    let (Some(sig_b64), Some(pubkey_b64)) = (&info.signature, &info.pubkey) else {
+        return false;
+    };
+
+    let Ok(sig_bytes) = URL_SAFE_NO_PAD.decode(sig_b64) else {
+        return false;
+    };
+    let Ok(pubkey_bytes) = URL_SAFE_NO_PAD.decode(pubkey_b64) else {
+        return false;
+    };
+    let Ok(sig_array) = <[u8; 64]>::try_from(sig_bytes.as_slice()) else {
+        return false;
+    };
+    let Ok(pubkey_array) = <[u8; 32]>::try_from(pubkey_bytes.as_slice()) else {
+        return false;
+    };
+    let Ok(verifying_key) = VerifyingKey::from_bytes(&pubkey_array) else {
+        return false;
+    };
+
+    let signature = Signature::from_bytes(&sig_array);
+    verifying_key
+        .verify(&canonical_payload(info), &signature)
+        .is_ok()
+}
+
+/// Encodes a signed `CrashInfo` as a compact JWS-style token:
+/// `base64url(header).base64url(payload).base64url(signature)`, so it can be
+/// shipped to a collector that doesn't share this workspace. The signature
+/// here covers `header.payload` (standard JWS signing input), not just the
+/// fields covered by `info.signature`.
+pub fn encode_crash_jws(info: &CrashInfo, workspace_dir: &Path) -> Option<String> {
+    let header_b64 = URL_SAFE_NO_PAD.encode(br#"{"alg":"EdDSA","typ":"JWT"}"#);
+    let payload_b64 = URL_SAFE_NO_PAD.encode(serde_json::to_vec(info).ok()?);
+    let signing_input = format!("{header_b64}.{payload_b64}");
+
+    let signing_key = load_or_generate_keypair(workspace_dir);
+    let signature = signing_key.sign(signing_input.as_bytes());
+    let sig_b64 = URL_SAFE_NO_PAD.encode(signature.to_bytes());
+
+    Some(format!("{signing_input}.{sig_b64}"))
+}
+
+/// Decodes a token produced by `encode_crash_jws`, verifying both the outer
+/// JWS signature (over `header.payload`) and the inner field-level
+/// signature embedded in the payload. Returns `None` if the token is
+/// malformed or either signature fails to check out.
+pub fn decode_crash_jws(token: &str) -> Option<CrashInfo> {
+    let mut parts = token.splitn(3, '.');
+    let header_b64 = parts.next()?;
+    let payload_b64 = parts.next()?;
+    let sig_b64 = parts.next()?;
+
+    let payload_bytes = URL_SAFE_NO_PAD.decode(payload_b64).ok()?;
+    let info: CrashInfo = serde_json::from_slice(&payload_bytes).ok()?;
+
+    let pubkey_bytes = URL_SAFE_NO_PAD.decode(info.pubkey.as_deref()?).ok()?;
+    let pubkey_array = <[u8; 32]>::try_from(pubkey_bytes.as_slice()).ok()?;
+    let verifying_key = VerifyingKey::from_bytes(&pubkey_array).ok()?;
+
+    let sig_bytes = URL_SAFE_NO_PAD.decode(sig_b64).ok()?;
+    let sig_array = <[u8; 64]>::try_from(sig_bytes.as_slice()).ok()?;
+    let signature = Signature::from_bytes(&sig_array);
+
+    let signing_input = format!("{header_b64}.{payload_b64}");
+    verifying_key
+        .verify(signing_input.as_bytes(), &signature)
+        .ok()?;
+
+    verify_crash(&info).then_some(info)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn fresh_info(error: &str, recovery_detail: Option<&str>) -> CrashInfo {
+        CrashInfo {
+            timestamp: chrono::Utc::now().to_rfc3339(),
+            error: error.to_string(),
+            recovery_detail: recovery_detail.map(ToString::to_string),
+            signature: None,
+            pubkey: None,
+        }
+    }
+
+    #[test]
+    fn signs_and_verifies_round_trip() {
+        let dir = tempdir().unwrap();
+        let mut info = fresh_info("panic in worker thread", None);
+
+        sign_crash(&mut info, dir.path());
+        assert!(info.signature.is_some());
+        assert!(info.pubkey.is_some());
+        assert!(verify_crash(&info));
+    }
+
+    #[test]
+    fn rejects_tampered_payload() {
+        let dir = tempdir().unwrap();
+        let mut info = fresh_info("original error", None);
+        sign_crash(&mut info, dir.path());
+
+        info.error = "forged error".to_string();
+        assert!(!verify_crash(&info));
+    }
+
+    #[test]
+    fn jws_round_trip_through_encode_decode() {
+        let dir = tempdir().unwrap();
+        let mut info = fresh_info("disk full", Some("freed temp files"));
+        sign_crash(&mut info, dir.path());
+
+        let token = encode_crash_jws(&info, dir.path()).expect("signed info encodes");
+        assert_eq!(token.matches('.').count(), 2);
+
+        let decoded = decode_crash_jws(&token).expect("valid token decodes");
+        assert_eq!(decoded.error, "disk full");
+    }
+
+    #[test]
+    fn unsigned_info_does_not_verify() {
+        let info = fresh_info("no signature", None);
+        assert!(!verify_crash(&info));
+    }
+
+    #[test]
+    fn jws_rejects_tampered_outer_signature() {
+        let dir = tempdir().unwrap();
+        let mut info = fresh_info("disk full", None);
+        sign_crash(&mut info, dir.path());
+        let token = encode_crash_jws(&info, dir.path()).expect("signed info encodes");
+
+        let mut segments: Vec<&str> = token.splitn(3, '.').collect();
+        let forged_sig = if segments[2].starts_with('A') { "B" } else { "A" };
+        segments[2] = forged_sig;
+        let tampered = segments.join(".");
+
+        assert!(decode_crash_jws(&tampered).is_none());
+    }
+}
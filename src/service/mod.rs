@@ -39,6 +39,13 @@ fn install(config: &Config) -> Result<()> {
 }
 
 fn start(config: &Config) -> Result<()> {
+    // Recompute the backoff decision fresh before this restart attempt. It's
+    // only otherwise refreshed from inside `record_crash`/`consume_crash`, so
+    // without this an admin-triggered restart or deploy bounce that happens
+    // after a crash storm has cooled down would still sleep on the stale
+    // multi-minute value left over from that storm.
+    crate::resilience::refresh_backoff_state(&daemon_workspace_dir(config));
+
     if cfg!(target_os = "macos") {
         let plist = macos_service_file()?;
         run_checked(Command::new("launchctl").arg("load").arg("-w").arg(&plist))?;
@@ -287,10 +294,8 @@ fn install_linux(config: &Config) -> Result<()> {
     }
 
     let exe = std::env::current_exe().context("Failed to resolve current executable")?;
-    let unit = format!(
-        "[Unit]\nDescription=ZeroClaw daemon\nAfter=network.target\n\n[Service]\nType=simple\nExecStart={} daemon\nRestart=always\nRestartSec=3\n\n[Install]\nWantedBy=default.target\n",
-        exe.display()
-    );
+    let backoff_file = crate::resilience::restart_backoff_file(&daemon_workspace_dir(config));
+    let unit = build_linux_unit(&exe.display().to_string(), &backoff_file.display().to_string());
 
     fs::write(&file, unit)?;
     let _ = run_checked(Command::new("systemctl").args(["--user", "daemon-reload"]));
@@ -300,6 +305,16 @@ fn install_linux(config: &Config) -> Result<()> {
     Ok(())
 }
 
+/// Builds the systemd unit text. `ExecStartPre` consults the same
+/// `restart_backoff_secs` file `resilience::refresh_backoff_state` writes,
+/// so a crash-looping daemon is actually throttled instead of being
+/// respawned immediately by `Restart=always`.
+fn build_linux_unit(exe_path: &str, backoff_file: &str) -> String {
+    format!(
+        "[Unit]\nDescription=ZeroClaw daemon\nAfter=network.target\n\n[Service]\nType=simple\nExecStartPre=-/bin/sh -c 'test -s \"{backoff_file}\" && sleep \"$(cat \"{backoff_file}\")\" || true'\nExecStart={exe_path} daemon\nRestart=always\nRestartSec=3\n\n[Install]\nWantedBy=default.target\n"
+    )
+}
+
 fn install_windows(config: &Config) -> Result<()> {
     let exe = std::env::current_exe().context("Failed to resolve current executable")?;
     let logs_dir = config
@@ -320,12 +335,15 @@ fn install_windows(config: &Config) -> Result<()> {
         .map_or_else(|| PathBuf::from("."), PathBuf::from)
         .join("daemon_state.json");
 
+    let backoff_file = crate::resilience::restart_backoff_file(&daemon_workspace_dir(config));
+
     let wrapper_content = build_windows_supervisor_wrapper(
         &exe.display().to_string(),
         &state_file.display().to_string(),
         &stdout_log.display().to_string(),
         &stderr_log.display().to_string(),
         &supervisor_log.display().to_string(),
+        &backoff_file.display().to_string(),
     );
     fs::write(&wrapper, &wrapper_content)?;
 
@@ -371,7 +389,7 @@ fn install_windows(config: &Config) -> Result<()> {
             println!("✅ Installed Windows scheduled task: {}", task_name);
             println!("   Wrapper: {}", wrapper.display());
             println!("   Logs: {}", logs_dir.display());
-            println!("   Restart policy: always (supervisor loop, 5s delay)");
+            println!("   Restart policy: always (supervisor loop, crash-loop backoff)");
             println!("   Start with: zeroclaw service start");
             Ok(())
         }
@@ -383,7 +401,7 @@ fn install_windows(config: &Config) -> Result<()> {
             );
             println!("   Wrapper: {}", wrapper.display());
             println!("   Logs: {}", logs_dir.display());
-            println!("   Restart policy: always (supervisor loop, 5s delay)");
+            println!("   Restart policy: always (supervisor loop, crash-loop backoff)");
             println!("   Note: scheduled task unavailable ({task_err})");
             println!("   Start with: zeroclaw service start");
             Ok(())
@@ -391,6 +409,16 @@ fn install_windows(config: &Config) -> Result<()> {
     }
 }
 
+/// The workspace `resilience` writes its crash/backoff state into.
+/// Mirrors the directory the daemon's logs and state file already live in,
+/// since this crate has no separate workspace-dir config field.
+fn daemon_workspace_dir(config: &Config) -> PathBuf {
+    config
+        .config_path
+        .parent()
+        .map_or_else(|| PathBuf::from("."), PathBuf::from)
+}
+
 fn windows_wrapper_path(config: &Config) -> PathBuf {
     config
         .config_path
@@ -471,6 +499,7 @@ fn build_windows_supervisor_wrapper(
     stdout_log: &str,
     stderr_log: &str,
     supervisor_log: &str,
+    backoff_file: &str,
 ) -> String {
     format!(
         "@echo off\r\n\
@@ -480,6 +509,7 @@ set \"ZEROCLAW_STATE={state_file}\"\r\n\
 set \"ZEROCLAW_STDOUT={stdout_log}\"\r\n\
 set \"ZEROCLAW_STDERR={stderr_log}\"\r\n\
 set \"ZEROCLAW_SUPERVISOR={supervisor_log}\"\r\n\
+set \"ZEROCLAW_BACKOFF={backoff_file}\"\r\n\
 set /a RESTART_COUNT=0\r\n\
 :run_loop\r\n\
 set /a RESTART_COUNT+=1\r\n\
@@ -487,8 +517,14 @@ echo [%date% %time%] starting daemon attempt !RESTART_COUNT!>>\"!ZEROCLAW_SUPERV
 if exist \"!ZEROCLAW_STATE!\" del /f /q \"!ZEROCLAW_STATE!\" >nul 2>&1\r\n\
 \"!ZEROCLAW_EXE!\" daemon >>\"!ZEROCLAW_STDOUT!\" 2>>\"!ZEROCLAW_STDERR!\"\r\n\
 set \"EXIT_CODE=!errorlevel!\"\r\n\
-echo [%date% %time%] daemon exited with code !EXIT_CODE!; restarting in 5s>>\"!ZEROCLAW_SUPERVISOR!\"\r\n\
-timeout /t 5 /nobreak >nul\r\n\
+set \"WAIT_SECS=5\"\r\n\
+if exist \"!ZEROCLAW_BACKOFF!\" (\r\n\
+  set /p WAIT_SECS=<\"!ZEROCLAW_BACKOFF!\"\r\n\
+)\r\n\
+if \"!WAIT_SECS!\"==\"\" set \"WAIT_SECS=5\"\r\n\
+if \"!WAIT_SECS!\"==\"0\" set \"WAIT_SECS=5\"\r\n\
+echo [%date% %time%] daemon exited with code !EXIT_CODE!; restarting in !WAIT_SECS!s (crash-loop backoff)>>\"!ZEROCLAW_SUPERVISOR!\"\r\n\
+timeout /t !WAIT_SECS! /nobreak >nul\r\n\
 goto run_loop\r\n"
     )
 }
@@ -596,12 +632,24 @@ mod tests {
             "C:\\logs\\daemon.stdout.log",
             "C:\\logs\\daemon.stderr.log",
             "C:\\logs\\daemon.supervisor.log",
+            "C:\\data\\restart_backoff_secs",
         );
         assert!(wrapper.contains(":run_loop"));
         assert!(wrapper.contains("goto run_loop"));
         assert!(wrapper.contains("daemon exited with code"));
         assert!(wrapper.contains("del /f /q"));
-        assert!(wrapper.contains("timeout /t 5 /nobreak"));
+        assert!(wrapper.contains("timeout /t !WAIT_SECS! /nobreak"));
+        assert!(wrapper.contains("ZEROCLAW_BACKOFF=C:\\data\\restart_backoff_secs"));
+        assert!(wrapper.contains("set /p WAIT_SECS=<"));
+    }
+
+    #[test]
+    fn linux_unit_waits_on_backoff_file_before_restart() {
+        let unit = build_linux_unit("/usr/bin/zeroclaw", "/home/user/.zeroclaw/restart_backoff_secs");
+        assert!(unit.contains("ExecStartPre=-/bin/sh -c"));
+        assert!(unit.contains("restart_backoff_secs"));
+        assert!(unit.contains("Restart=always"));
+        assert!(unit.contains("ExecStart=/usr/bin/zeroclaw daemon"));
     }
 
     #[cfg(target_os = "windows")]
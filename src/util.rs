@@ -53,6 +53,12 @@ pub struct OpenClawProfile {
     pub model: Option<String>,
     pub api_url: Option<String>,
     pub api_key: Option<String>,
+    /// Every entry under `models.providers`, classified by kind so callers
+    /// can build provider-native request bodies via `providers::Backend`.
+    pub providers: Vec<crate::providers::NamedProvider>,
+    /// Fallback model list (`provider/model` strings) from `agents.defaults.model.fallback`,
+    /// tried in order if the primary provider fails.
+    pub fallback: Vec<String>,
 }
 
 fn parse_openclaw_profile(config_path: &std::path::Path) -> Option<OpenClawProfile> {
@@ -80,9 +86,46 @@ fn parse_openclaw_profile(config_path: &std::path::Path) -> Option<OpenClawProfi
         (None, None)
     };
 
+    let fallback = json
+        .get("agents")
+        .and_then(|v| v.get("defaults"))
+        .and_then(|v| v.get("model"))
+        .and_then(|v| v.get("fallback"))
+        .and_then(serde_json::Value::as_array)
+        .map(|entries| {
+            entries
+                .iter()
+                .filter_map(serde_json::Value::as_str)
+                .map(ToString::to_string)
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let providers_node = json.get("models").and_then(|v| v.get("providers"));
+    let providers = providers_node
+        .and_then(serde_json::Value::as_object)
+        .map(|entries| {
+            entries
+                .iter()
+                .map(|(name, node)| crate::providers::NamedProvider {
+                    name: name.clone(),
+                    kind: crate::providers::classify_provider(node),
+                    base_url: node
+                        .get("baseUrl")
+                        .and_then(serde_json::Value::as_str)
+                        .map(ToString::to_string),
+                    api_key: node
+                        .get("apiKey")
+                        .and_then(serde_json::Value::as_str)
+                        .map(ToString::to_string),
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
     let provider_node = provider
         .as_deref()
-        .and_then(|p| json.get("models")?.get("providers")?.get(p));
+        .and_then(|p| providers_node?.get(p));
     let api_url = provider_node
         .and_then(|v| v.get("baseUrl"))
         .and_then(serde_json::Value::as_str)
@@ -99,9 +142,48 @@ fn parse_openclaw_profile(config_path: &std::path::Path) -> Option<OpenClawProfi
         model,
         api_url,
         api_key,
+        providers,
+        fallback,
     })
 }
 
+impl OpenClawProfile {
+    /// Builds the ordered list of `(provider, model, request body)` candidates
+    /// to try for `prompt`: the primary `provider`/`model` first, then each
+    /// `agents.defaults.model.fallback` entry in order. A candidate is
+    /// skipped if its provider name has no matching entry under
+    /// `models.providers`, since there's nothing to build a request against.
+    ///
+    /// Callers drive the actual retry: send the request for the first
+    /// candidate, and on failure move to the next one.
+    pub fn build_request_candidates(
+        &self,
+        prompt: &str,
+        params: &crate::providers::RequestParams,
+    ) -> Vec<(crate::providers::NamedProvider, String, serde_json::Value)> {
+        let primary = self.provider.as_deref().zip(self.model.as_deref());
+        let fallbacks = self
+            .fallback
+            .iter()
+            .filter_map(|entry| entry.split_once('/'));
+
+        primary
+            .into_iter()
+            .chain(fallbacks)
+            .filter_map(|(provider_name, model)| {
+                let provider = self.providers.iter().find(|p| p.name == provider_name)?.clone();
+                let body = crate::providers::Backend::build_request_body(
+                    &provider.kind,
+                    model,
+                    prompt,
+                    params,
+                );
+                Some((provider, model.to_string(), body))
+            })
+            .collect()
+    }
+}
+
 /// Resolves the workspace directory specified in the OpenClaw configuration (`~/.openclaw/openclaw.json`), if available.
 pub fn resolve_openclaw_workspace() -> Option<PathBuf> {
     detect_openclaw_profile().and_then(|profile| profile.workspace_dir)
@@ -219,4 +301,58 @@ mod tests {
         // Edge case: max_chars = 0
         assert_eq!(truncate_with_ellipsis("hello", 0), "...");
     }
+
+    fn test_profile(providers: Vec<crate::providers::NamedProvider>, fallback: Vec<&str>) -> OpenClawProfile {
+        OpenClawProfile {
+            source_path: PathBuf::from("/tmp/openclaw.json"),
+            workspace_dir: None,
+            provider: Some("primary".to_string()),
+            model: Some("primary-model".to_string()),
+            api_url: None,
+            api_key: None,
+            providers,
+            fallback: fallback.into_iter().map(ToString::to_string).collect(),
+        }
+    }
+
+    fn named_provider(name: &str, kind: crate::providers::ProviderKind) -> crate::providers::NamedProvider {
+        crate::providers::NamedProvider {
+            name: name.to_string(),
+            kind,
+            base_url: None,
+            api_key: None,
+        }
+    }
+
+    #[test]
+    fn build_request_candidates_tries_primary_then_fallback_in_order() {
+        let providers = vec![
+            named_provider("primary", crate::providers::ProviderKind::OpenAiCompat),
+            named_provider("backup", crate::providers::ProviderKind::Ollama),
+        ];
+        let profile = test_profile(providers, vec!["backup/backup-model"]);
+
+        let candidates =
+            profile.build_request_candidates("hi", &crate::providers::RequestParams::default());
+
+        assert_eq!(candidates.len(), 2);
+        assert_eq!(candidates[0].0.name, "primary");
+        assert_eq!(candidates[0].1, "primary-model");
+        assert_eq!(candidates[0].2["messages"][0]["content"], "hi");
+        assert_eq!(candidates[1].0.name, "backup");
+        assert_eq!(candidates[1].1, "backup-model");
+        assert_eq!(candidates[1].2["prompt"], "hi");
+    }
+
+    #[test]
+    fn build_request_candidates_skips_fallback_entries_with_no_matching_provider() {
+        let providers = vec![named_provider("primary", crate::providers::ProviderKind::OpenAiCompat)];
+        let profile = test_profile(providers, vec!["unknown/some-model"]);
+
+        let candidates =
+            profile.build_request_candidates("hi", &crate::providers::RequestParams::default());
+
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].0.name, "primary");
+    }
 }